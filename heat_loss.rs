@@ -6,44 +6,209 @@ struct MaterialProperties {
     thickness: f64,
 }
 
-#[derive(Debug)]
+// Area and temperature fields are unit-tagged strings (e.g. "1800 ft²", "70 °F") parsed
+// to SI via `parse_area_m2`/`parse_temperature_c` at the input boundary, so the
+// calculation core only ever works in metres² and kelvin deltas.
+#[derive(Debug, Clone)]
 struct CalculationInput {
-    sqft_roof: f64,
-    sqft_walls: f64,
+    roof_area: String,
+    wall_area: String,
     roof_material_type: String,
     wall_material_type: String,
-    ambient_temp_f: f64,
-    t_inside_f: f64,
+    ambient_temp: String,
+    t_inside: String,
     duration_hours: u64,
     insulation_r_value: String,
     air_changes_per_hour: f64,
-    window_area_sqft: f64,
+    zone_volume_cubic_ft: f64,
+    window_area: String,
     window_type: String,
-    electricity_cost_per_kwh: f64,
+    thermal_mass_kj_per_k: f64,
+    ducts: Vec<Duct>,
+    heating_system: HeatingSystem,
+    // Conditioned floor area, for HLP. Distinct from `roof_area`, which sizes roof fabric
+    // loss and can differ from floor area on lofted or multi-storey buildings.
+    floor_area: String,
+}
+
+// The fuel and equipment converting useful heat demand into purchased energy. A COP-3
+// heat pump divides demand by 3 to get delivered energy; a 0.9-efficient gas boiler
+// divides by 0.9.
+#[derive(Debug, Clone)]
+struct HeatingSystem {
+    fuel_type: FuelType,
+    unit_price: f64,
+    efficiency: f64,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum FuelType {
+    Electricity,
+    MainsGas,
+    HeatPump,
+    Oil,
+}
+
+impl FuelType {
+    // The unit `unit_price` is quoted in for this fuel.
+    fn price_unit(&self) -> &'static str {
+        match self {
+            FuelType::MainsGas => "therm",
+            FuelType::Electricity | FuelType::HeatPump | FuelType::Oil => "kWh",
+        }
+    }
+}
+
+// A run of MVHR (mechanical ventilation with heat recovery) ductwork, modelled as a
+// hollow insulated cylinder per ISO 12241. `length_inside_envelope_m` is duct that
+// runs through heated space (its loss is a gain to the zone); `length_outside_envelope_m`
+// runs through unheated space (its loss is a loss to the zone).
+#[derive(Debug, Clone)]
+struct Duct {
+    internal_diameter_m: f64,
+    insulation_thickness_m: f64,
+    insulation_conductivity: f64,
+    length_inside_envelope_m: f64,
+    length_outside_envelope_m: f64,
+    reflective_jacket: bool,
 }
 
 #[derive(Debug)]
 struct CalculationResult {
     total_cost: f64,
     q_total_kwh: f64,
+    delivered_energy_kwh: f64,
+    htc_w_per_k: f64,
+    hlp_w_per_m2_k: f64,
+    breakdown: Vec<ElementBreakdown>,
+}
+
+// Conductance and energy contribution of a single fabric/ventilation element, for
+// reporting a fabric-vs-ventilation split instead of only the lumped total.
+#[derive(Debug)]
+struct ElementBreakdown {
+    element: String,
+    conductance_w_per_k: f64,
+    q_kwh: f64,
 }
 
 // Constants (example)
 const SQFT_TO_SQM: f64 = 0.092903;
+const CUFT_TO_CUM: f64 = SQFT_TO_SQM * 0.3048;
 const HOURS_TO_SECONDS: u64 = 3600;
 const F_TO_C: f64 = 1.8;
 const JOULES_TO_KWH: f64 = 3600000.0;
+const RHO_AIR: f64 = 1.2; // kg/m3
+const C_AIR: f64 = 1006.0; // J/(kg*K)
+const DUCT_H_INTERNAL: f64 = 15.5; // W/(m2*K), internal surface heat transfer coefficient
+const DUCT_H_EXTERNAL_REFLECTIVE: f64 = 5.7; // W/(m2*K), reflective jacket
+const DUCT_H_EXTERNAL_NON_REFLECTIVE: f64 = 10.0; // W/(m2*K), non-reflective jacket
+const THERM_TO_KWH: f64 = 29.3071;
 
-fn calculate_heat_loss(
-    area: f64,
-    delta_t_c: f64,
-    material: &MaterialProperties,
-    insulation_r_value_si: f64,
-) -> f64 {
-    area * delta_t_c / (material.thickness / material.thermal_conductivity + insulation_r_value_si)
+// Converts useful heat demand into delivered/purchased energy and its cost, accounting
+// for the heating system's efficiency (or COP) and the fuel's pricing unit.
+fn calculate_delivered_energy_and_cost(q_total_kwh: f64, heating_system: &HeatingSystem) -> (f64, f64) {
+    let delivered_energy_kwh = q_total_kwh / heating_system.efficiency;
+    let price_per_kwh = match heating_system.fuel_type.price_unit() {
+        "therm" => heating_system.unit_price / THERM_TO_KWH,
+        _ => heating_system.unit_price,
+    };
+    (delivered_energy_kwh, delivered_energy_kwh * price_per_kwh)
 }
 
-fn compute_specific_heat_loss(input: &CalculationInput) -> Result<CalculationResult, &'static str> {
+fn fahrenheit_to_celsius(temp_f: f64) -> f64 {
+    (temp_f - 32.0) / F_TO_C
+}
+
+// --- Unit-tagged input parsing -------------------------------------------------
+// Splits a tagged quantity like "1800 ft²" or "70 °F" into its leading numeric value and
+// unit suffix (R-values, whose unit is a prefix, go through `parse_r_value_si`), so conversions to SI happen once at the input boundary instead of being
+// scattered through the calculation core.
+fn split_value_and_unit(tagged: &str) -> Result<(f64, &str), &'static str> {
+    let trimmed = tagged.trim();
+    let split_at = trimmed
+        .find(|c: char| !(c.is_ascii_digit() || c == '.' || c == '-'))
+        .ok_or("Missing unit suffix")?;
+    let (value_str, unit_str) = trimmed.split_at(split_at);
+    let value = value_str.trim().parse::<f64>().map_err(|_| "Invalid numeric value")?;
+    Ok((value, unit_str.trim()))
+}
+
+fn parse_area_m2(tagged: &str) -> Result<f64, &'static str> {
+    let (value, unit) = split_value_and_unit(tagged)?;
+    if value <= 0.0 {
+        return Err("Area must be positive");
+    }
+    match unit {
+        "ft²" | "sqft" => Ok(value * SQFT_TO_SQM),
+        "m²" | "sqm" => Ok(value),
+        _ => Err("Unknown area unit"),
+    }
+}
+
+fn parse_temperature_c(tagged: &str) -> Result<f64, &'static str> {
+    let (value, unit) = split_value_and_unit(tagged)?;
+    match unit {
+        "°F" => Ok(fahrenheit_to_celsius(value)),
+        "°C" => Ok(value),
+        _ => Err("Unknown temperature unit"),
+    }
+}
+
+// Parses an R-value numerically (`R13` => `13 * 0.1761` m²·K/W) instead of matching a
+// fixed band, and also accepts the SI-native `RSI-<value>` form directly.
+fn parse_r_value_si(tagged: &str) -> Result<f64, &'static str> {
+    let trimmed = tagged.trim();
+    if let Some(rsi) = trimmed.strip_prefix("RSI-") {
+        let value: f64 = rsi.parse().map_err(|_| "Invalid RSI value")?;
+        if value <= 0.0 {
+            return Err("R-value must be positive");
+        }
+        return Ok(value);
+    }
+    if let Some(r_imperial) = trimmed.strip_prefix('R') {
+        let r_value: f64 = r_imperial.parse().map_err(|_| "Invalid R-value")?;
+        if r_value <= 0.0 {
+            return Err("R-value must be positive");
+        }
+        return Ok(r_value * 0.1761);
+    }
+    Err("Invalid insulation R-value")
+}
+
+// Per-metre thermal resistance of a duct's radial conduction path (internal surface,
+// insulation, external surface), in m*K/W.
+fn calculate_duct_resistance_per_metre(duct: &Duct) -> f64 {
+    let d_int = duct.internal_diameter_m;
+    let d_out = d_int + 2.0 * duct.insulation_thickness_m;
+    let h_ext = if duct.reflective_jacket { DUCT_H_EXTERNAL_REFLECTIVE } else { DUCT_H_EXTERNAL_NON_REFLECTIVE };
+
+    let r_int = 1.0 / (DUCT_H_INTERNAL * std::f64::consts::PI * d_int);
+    let r_ins = (d_out / d_int).ln() / (2.0 * std::f64::consts::PI * duct.insulation_conductivity);
+    let r_ext = 1.0 / (h_ext * std::f64::consts::PI * d_out);
+
+    r_int + r_ins + r_ext
+}
+
+// Net conductance (W/K) a single duct run contributes to the zone: the section outside
+// the envelope loses heat, while the section inside the envelope returns it as a gain,
+// so this can come out negative when more duct runs inside than outside.
+fn calculate_duct_ua(duct: &Duct) -> f64 {
+    (duct.length_outside_envelope_m - duct.length_inside_envelope_m) / calculate_duct_resistance_per_metre(duct)
+}
+
+// Conductances (W/K) of every heat-loss path shared by `compute_specific_heat_loss` and
+// `compute_transient_heat_loss`, resolved once from the material/window-U lookups and
+// R-value parsing so both entry points stay in lockstep as elements are added.
+struct EnvelopeConductances {
+    ua_roof: f64,
+    ua_walls: f64,
+    ua_windows: f64,
+    ua_ventilation: f64,
+    ua_ductwork: f64,
+}
+
+fn resolve_envelope_conductances(input: &CalculationInput) -> Result<EnvelopeConductances, &'static str> {
     let roof_materials = HashMap::from([
         ("asphalt".to_string(), MaterialProperties { thermal_conductivity: 0.2, thickness: 0.005 }),
         ("wood".to_string(), MaterialProperties { thermal_conductivity: 0.08, thickness: 0.01 }),
@@ -55,50 +220,413 @@ fn compute_specific_heat_loss(input: &CalculationInput) -> Result<CalculationRes
         // Add other materials as needed
     ]);
 
+    let window_u_values = HashMap::from([
+        ("single".to_string(), 5.7),
+        ("double".to_string(), 2.8),
+        ("triple".to_string(), 1.6),
+        // Add other glazing types as needed
+    ]);
+
     let roof_material = roof_materials.get(&input.roof_material_type).ok_or("Invalid roof material type")?;
     let wall_material = wall_materials.get(&input.wall_material_type).ok_or("Invalid wall material type")?;
+    let window_u_value = window_u_values.get(&input.window_type).ok_or("Invalid window type")?;
 
-    let delta_t_c = (input.t_inside_f - input.ambient_temp_f) / F_TO_C;
-    let insulation_r_value_si = match input.insulation_r_value.as_str() {
-        "R13-R15" => 14.0 * 0.176110, // Convert to SI units
-        _ => return Err("Invalid insulation R-value"),
-    };
+    let insulation_r_value_si = parse_r_value_si(&input.insulation_r_value)?;
 
-    let area_roof_m2 = input.sqft_roof * SQFT_TO_SQM;
-    let area_walls_m2 = input.sqft_walls * SQFT_TO_SQM;
+    let area_roof_m2 = parse_area_m2(&input.roof_area)?;
+    let area_walls_m2 = parse_area_m2(&input.wall_area)?;
+    let area_window_m2 = parse_area_m2(&input.window_area)?;
+    let zone_volume_m3 = input.zone_volume_cubic_ft * CUFT_TO_CUM;
 
-    let q_roof = calculate_heat_loss(area_roof_m2, delta_t_c, roof_material, insulation_r_value_si);
-    let q_walls = calculate_heat_loss(area_walls_m2, delta_t_c, wall_material, insulation_r_value_si);
+    // Opaque surfaces use the resistance form (area / (thickness/conductivity + R));
+    // glazing uses the direct U-value form (area * u_value) since it has no separate
+    // R-value input. Both are resolved here as conductances (UA) so `htc_w_per_k` and the
+    // transient loop share one value instead of recomputing power from area/delta_t twice.
+    let ua_roof = area_roof_m2 / (roof_material.thickness / roof_material.thermal_conductivity + insulation_r_value_si);
+    let ua_walls = area_walls_m2 / (wall_material.thickness / wall_material.thermal_conductivity + insulation_r_value_si);
+    let ua_windows = area_window_m2 * window_u_value;
+    let ua_ventilation = (input.air_changes_per_hour * zone_volume_m3 / 3600.0) * RHO_AIR * C_AIR;
+    let ua_ductwork: f64 = input.ducts.iter().map(calculate_duct_ua).sum();
+
+    Ok(EnvelopeConductances {
+        ua_roof,
+        ua_walls,
+        ua_windows,
+        ua_ventilation,
+        ua_ductwork,
+    })
+}
 
-    let q_total_joules = (q_roof + q_walls) * input.duration_hours as f64 * HOURS_TO_SECONDS as f64;
+fn compute_specific_heat_loss(input: &CalculationInput) -> Result<CalculationResult, &'static str> {
+    let envelope = resolve_envelope_conductances(input)?;
+
+    let delta_t_c = parse_temperature_c(&input.t_inside)? - parse_temperature_c(&input.ambient_temp)?;
+
+    let q_roof = envelope.ua_roof * delta_t_c;
+    let q_walls = envelope.ua_walls * delta_t_c;
+    let q_ventilation = envelope.ua_ventilation * delta_t_c;
+    let q_windows = envelope.ua_windows * delta_t_c;
+
+    let q_ductwork = envelope.ua_ductwork * delta_t_c;
+
+    let q_total_joules = (q_roof + q_walls + q_ventilation + q_windows + q_ductwork) * input.duration_hours as f64 * HOURS_TO_SECONDS as f64;
     let q_total_kwh = q_total_joules / JOULES_TO_KWH;
 
-    let total_cost = q_total_kwh * input.electricity_cost_per_kwh;
+    let (delivered_energy_kwh, total_cost) = calculate_delivered_energy_and_cost(q_total_kwh, &input.heating_system);
+
+    let htc_w_per_k = envelope.ua_roof + envelope.ua_walls + envelope.ua_windows + envelope.ua_ventilation + envelope.ua_ductwork;
+    let hlp_w_per_m2_k = htc_w_per_k / parse_area_m2(&input.floor_area)?;
+
+    let power_to_kwh = |power_w: f64| power_w * input.duration_hours as f64 * HOURS_TO_SECONDS as f64 / JOULES_TO_KWH;
+    let breakdown = vec![
+        ElementBreakdown { element: "roof".to_string(), conductance_w_per_k: envelope.ua_roof, q_kwh: power_to_kwh(q_roof) },
+        ElementBreakdown { element: "walls".to_string(), conductance_w_per_k: envelope.ua_walls, q_kwh: power_to_kwh(q_walls) },
+        ElementBreakdown { element: "windows".to_string(), conductance_w_per_k: envelope.ua_windows, q_kwh: power_to_kwh(q_windows) },
+        ElementBreakdown { element: "ventilation".to_string(), conductance_w_per_k: envelope.ua_ventilation, q_kwh: power_to_kwh(q_ventilation) },
+        ElementBreakdown { element: "ductwork".to_string(), conductance_w_per_k: envelope.ua_ductwork, q_kwh: power_to_kwh(q_ductwork) },
+    ];
 
     Ok(CalculationResult {
         total_cost,
         q_total_kwh,
+        delivered_energy_kwh,
+        htc_w_per_k,
+        hlp_w_per_m2_k,
+        breakdown,
     })
 }
 
+// Transient alternative to `compute_specific_heat_loss`: instead of assuming a fixed
+// indoor/outdoor delta for the whole duration, steps through an ambient temperature
+// series with a lumped-capacitance model so indoor temperature can drift between steps.
+fn compute_transient_heat_loss(
+    input: &CalculationInput,
+    ambient_temps_f: &[f64],
+    timestep_hours: f64,
+    heating_power_w: f64,
+) -> Result<CalculationResult, &'static str> {
+    let envelope = resolve_envelope_conductances(input)?;
+    let ua_total = envelope.ua_roof + envelope.ua_walls + envelope.ua_windows + envelope.ua_ventilation + envelope.ua_ductwork;
+
+    let thermal_capacitance_j_per_k = input.thermal_mass_kj_per_k * 1000.0;
+    let dt_seconds = timestep_hours * HOURS_TO_SECONDS as f64;
+
+    let mut t_inside_c = parse_temperature_c(&input.t_inside)?;
+    let mut q_total_kwh = 0.0;
+
+    for &ambient_f in ambient_temps_f {
+        let t_outside_c = fahrenheit_to_celsius(ambient_f);
+        let q_loss_w = ua_total * (t_inside_c - t_outside_c);
+        t_inside_c += (heating_power_w - q_loss_w) * dt_seconds / thermal_capacitance_j_per_k;
+        q_total_kwh += heating_power_w * dt_seconds / JOULES_TO_KWH;
+    }
+
+    let (delivered_energy_kwh, total_cost) = calculate_delivered_energy_and_cost(q_total_kwh, &input.heating_system);
+
+    let htc_w_per_k = ua_total;
+    let hlp_w_per_m2_k = htc_w_per_k / parse_area_m2(&input.floor_area)?;
+
+    // Indoor temperature drifts over the run, so there's no single delta to split by
+    // element; approximate each element's share of the supplied heat (`q_total_kwh`) by its
+    // UA share of HTC.
+    let element_share = |ua_element: f64| ua_element / ua_total * q_total_kwh;
+    let breakdown = vec![
+        ElementBreakdown { element: "roof".to_string(), conductance_w_per_k: envelope.ua_roof, q_kwh: element_share(envelope.ua_roof) },
+        ElementBreakdown { element: "walls".to_string(), conductance_w_per_k: envelope.ua_walls, q_kwh: element_share(envelope.ua_walls) },
+        ElementBreakdown { element: "windows".to_string(), conductance_w_per_k: envelope.ua_windows, q_kwh: element_share(envelope.ua_windows) },
+        ElementBreakdown { element: "ventilation".to_string(), conductance_w_per_k: envelope.ua_ventilation, q_kwh: element_share(envelope.ua_ventilation) },
+        ElementBreakdown { element: "ductwork".to_string(), conductance_w_per_k: envelope.ua_ductwork, q_kwh: element_share(envelope.ua_ductwork) },
+    ];
+
+    Ok(CalculationResult {
+        total_cost,
+        q_total_kwh,
+        delivered_energy_kwh,
+        htc_w_per_k,
+        hlp_w_per_m2_k,
+        breakdown,
+    })
+}
+
+// A single retrofit to evaluate against a baseline `CalculationInput`. Only the fields
+// a user might change for a given upgrade are `Some`; everything else carries over from
+// the baseline. `upgrade_cost` is the one-off cost of making the change, used to derive
+// a simple payback period from the annualized savings.
+#[derive(Debug)]
+struct RetrofitScenario {
+    name: String,
+    insulation_r_value: Option<String>,
+    window_type: Option<String>,
+    air_changes_per_hour: Option<f64>,
+    upgrade_cost: f64,
+}
+
+#[derive(Debug)]
+struct RetrofitComparison {
+    name: String,
+    q_total_kwh: f64,
+    total_cost: f64,
+    savings_kwh: f64,
+    savings_cost: f64,
+    payback_years: f64,
+}
+
+fn apply_retrofit_scenario(baseline: &CalculationInput, scenario: &RetrofitScenario) -> CalculationInput {
+    let mut variant = baseline.clone();
+    if let Some(r_value) = &scenario.insulation_r_value {
+        variant.insulation_r_value = r_value.clone();
+    }
+    if let Some(window_type) = &scenario.window_type {
+        variant.window_type = window_type.clone();
+    }
+    if let Some(ach) = scenario.air_changes_per_hour {
+        variant.air_changes_per_hour = ach;
+    }
+    variant
+}
+
+// Runs `compute_specific_heat_loss` once per scenario and reports the savings versus the
+// baseline, sorted with the biggest savings first. For example, raising
+// `insulation_r_value` from "R13" to "R30" answers "what do I save by upgrading the
+// wall/roof insulation?" in one call. `savings_kwh` and `savings_cost` cover the
+// baseline's `duration_hours`, not a year; `payback_years` scales the cost savings up to
+// a year (8760 hours) before dividing.
+fn compare_retrofit_scenarios(
+    baseline: &CalculationInput,
+    scenarios: &[RetrofitScenario],
+) -> Result<Vec<RetrofitComparison>, &'static str> {
+    let baseline_result = compute_specific_heat_loss(baseline)?;
+
+    let mut comparisons = Vec::new();
+    for scenario in scenarios {
+        let variant = apply_retrofit_scenario(baseline, scenario);
+        let result = compute_specific_heat_loss(&variant)?;
+
+        let savings_kwh = baseline_result.q_total_kwh - result.q_total_kwh;
+        let savings_cost = baseline_result.total_cost - result.total_cost;
+        let annual_savings_cost = savings_cost * 8760.0 / baseline.duration_hours as f64;
+        let payback_years = if annual_savings_cost > 0.0 {
+            scenario.upgrade_cost / annual_savings_cost
+        } else {
+            f64::INFINITY
+        };
+
+        comparisons.push(RetrofitComparison {
+            name: scenario.name.clone(),
+            q_total_kwh: result.q_total_kwh,
+            total_cost: result.total_cost,
+            savings_kwh,
+            savings_cost,
+            payback_years,
+        });
+    }
+
+    comparisons.sort_by(|a, b| b.savings_cost.partial_cmp(&a.savings_cost).unwrap_or(std::cmp::Ordering::Equal));
+    Ok(comparisons)
+}
+
 fn main() {
     let input = CalculationInput {
-        sqft_roof: 1800.0,
-        sqft_walls: 1500.0,
+        roof_area: "1800 ft²".to_string(),
+        wall_area: "1500 ft²".to_string(),
         roof_material_type: "asphalt".to_string(),
         wall_material_type: "wood".to_string(),
-        ambient_temp_f: 50.0,
-        t_inside_f: 70.0,
+        ambient_temp: "50 °F".to_string(),
+        t_inside: "70 °F".to_string(),
         duration_hours: 24,
-        insulation_r_value: "R13-R15".to_string(),
+        insulation_r_value: "R14".to_string(),
         air_changes_per_hour: 0.5,
-        window_area_sqft: 500.0,
+        zone_volume_cubic_ft: 14400.0,
+        window_area: "500 ft²".to_string(),
         window_type: "double".to_string(),
-        electricity_cost_per_kwh: 0.12,
+        thermal_mass_kj_per_k: 8000.0,
+        floor_area: "3400 ft²".to_string(),
+        ducts: vec![Duct {
+            internal_diameter_m: 0.15,
+            insulation_thickness_m: 0.025,
+            insulation_conductivity: 0.035,
+            length_inside_envelope_m: 8.0,
+            length_outside_envelope_m: 4.0,
+            reflective_jacket: false,
+        }],
+        heating_system: HeatingSystem {
+            fuel_type: FuelType::Electricity,
+            unit_price: 0.12,
+            efficiency: 1.0,
+        },
     };
 
     match compute_specific_heat_loss(&input) {
-        Ok(result) => println!("Total cost: {:.2}, Total kWh: {:.2}", result.total_cost, result.q_total_kwh),
+        Ok(result) => {
+            println!(
+                "Total cost: {:.2}, Heat demand: {:.2} kWh, Delivered energy: {:.2} kWh",
+                result.total_cost, result.q_total_kwh, result.delivered_energy_kwh
+            );
+            println!("HTC: {:.2} W/K, HLP: {:.2} W/m²·K", result.htc_w_per_k, result.hlp_w_per_m2_k);
+            for element in &result.breakdown {
+                println!("  {}: {:.2} W/K, {:.2} kWh", element.element, element.conductance_w_per_k, element.q_kwh);
+            }
+        }
         Err(e) => println!("Error: {}", e),
     }
+
+    // Diurnal ambient profile, one reading per hour, for the transient solver.
+    let hourly_ambient_f = vec![50.0, 48.0, 46.0, 45.0, 44.0, 45.0, 48.0, 52.0, 56.0, 58.0, 57.0, 53.0];
+    match compute_transient_heat_loss(&input, &hourly_ambient_f, 1.0, 2000.0) {
+        Ok(result) => println!("Transient cost: {:.2}, Transient kWh: {:.2}", result.total_cost, result.q_total_kwh),
+        Err(e) => println!("Error: {}", e),
+    }
+
+    // Compare running cost for the same fabric under a COP-3 heat pump instead of
+    // electric resistance heating.
+    let mut heat_pump_input = input.clone();
+    heat_pump_input.heating_system = HeatingSystem {
+        fuel_type: FuelType::HeatPump,
+        unit_price: 0.12,
+        efficiency: 3.0,
+    };
+    match compute_specific_heat_loss(&heat_pump_input) {
+        Ok(result) => println!(
+            "Heat pump cost: {:.2}, Delivered energy: {:.2} kWh",
+            result.total_cost, result.delivered_energy_kwh
+        ),
+        Err(e) => println!("Error: {}", e),
+    }
+
+    let mut gas_boiler_input = input.clone();
+    gas_boiler_input.heating_system = HeatingSystem {
+        fuel_type: FuelType::MainsGas,
+        unit_price: 1.45, // price per therm
+        efficiency: 0.9,
+    };
+    match compute_specific_heat_loss(&gas_boiler_input) {
+        Ok(result) => println!(
+            "Gas boiler cost: {:.2}, Delivered energy: {:.2} kWh",
+            result.total_cost, result.delivered_energy_kwh
+        ),
+        Err(e) => println!("Error: {}", e),
+    }
+
+    let mut oil_boiler_input = input.clone();
+    oil_boiler_input.heating_system = HeatingSystem {
+        fuel_type: FuelType::Oil,
+        unit_price: 0.09,
+        efficiency: 0.85,
+    };
+    match compute_specific_heat_loss(&oil_boiler_input) {
+        Ok(result) => println!(
+            "Oil boiler cost: {:.2}, Delivered energy: {:.2} kWh",
+            result.total_cost, result.delivered_energy_kwh
+        ),
+        Err(e) => println!("Error: {}", e),
+    }
+
+    let retrofit_scenarios = vec![
+        RetrofitScenario {
+            name: "Upgrade insulation to R30".to_string(),
+            insulation_r_value: Some("R30".to_string()),
+            window_type: None,
+            air_changes_per_hour: None,
+            upgrade_cost: 4200.0,
+        },
+        RetrofitScenario {
+            name: "Upgrade to triple-glazed windows".to_string(),
+            insulation_r_value: None,
+            window_type: Some("triple".to_string()),
+            air_changes_per_hour: None,
+            upgrade_cost: 6500.0,
+        },
+    ];
+    match compare_retrofit_scenarios(&input, &retrofit_scenarios) {
+        Ok(comparisons) => {
+            for comparison in &comparisons {
+                println!(
+                    "{}: {:.2} kWh, {:.2} cost (saves {:.2} kWh / {:.2} cost, payback {:.1} years)",
+                    comparison.name,
+                    comparison.q_total_kwh,
+                    comparison.total_cost,
+                    comparison.savings_kwh,
+                    comparison.savings_cost,
+                    comparison.payback_years
+                );
+            }
+        }
+        Err(e) => println!("Error: {}", e),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_duct(length_inside_envelope_m: f64, length_outside_envelope_m: f64) -> Duct {
+        Duct {
+            internal_diameter_m: 0.15,
+            insulation_thickness_m: 0.025,
+            insulation_conductivity: 0.035,
+            length_inside_envelope_m,
+            length_outside_envelope_m,
+            reflective_jacket: false,
+        }
+    }
+
+    #[test]
+    fn duct_ua_sign_flips_between_all_inside_and_all_outside() {
+        let all_inside = test_duct(10.0, 0.0);
+        let all_outside = test_duct(0.0, 10.0);
+        assert!(
+            calculate_duct_ua(&all_inside) < 0.0,
+            "a duct run entirely inside the envelope returns heat to the zone and should be a net gain (negative UA)"
+        );
+        assert!(
+            calculate_duct_ua(&all_outside) > 0.0,
+            "a duct run entirely outside the envelope should be a net loss (positive UA)"
+        );
+    }
+
+    #[test]
+    fn transient_matches_steady_state_under_constant_ambient() {
+        let input = CalculationInput {
+            roof_area: "900 m²".to_string(),
+            wall_area: "900 m²".to_string(),
+            roof_material_type: "asphalt".to_string(),
+            wall_material_type: "wood".to_string(),
+            ambient_temp: "0 °C".to_string(),
+            t_inside: "20 °C".to_string(),
+            duration_hours: 24,
+            insulation_r_value: "RSI-2.5".to_string(),
+            air_changes_per_hour: 0.5,
+            zone_volume_cubic_ft: 14400.0,
+            window_area: "50 m²".to_string(),
+            window_type: "double".to_string(),
+            thermal_mass_kj_per_k: 8000.0,
+            floor_area: "900 m²".to_string(),
+            ducts: vec![],
+            heating_system: HeatingSystem {
+                fuel_type: FuelType::Electricity,
+                unit_price: 0.12,
+                efficiency: 1.0,
+            },
+        };
+
+        let envelope = resolve_envelope_conductances(&input).unwrap();
+        let ua_total =
+            envelope.ua_roof + envelope.ua_walls + envelope.ua_windows + envelope.ua_ventilation + envelope.ua_ductwork;
+        let delta_t_c = 20.0;
+        let heating_power_w = ua_total * delta_t_c;
+
+        let steady = compute_specific_heat_loss(&input).unwrap();
+
+        // 0 °C, constant for the whole run.
+        let constant_ambient_f = vec![32.0; 24];
+        let transient = compute_transient_heat_loss(&input, &constant_ambient_f, 1.0, heating_power_w).unwrap();
+
+        assert!(
+            (transient.q_total_kwh - steady.q_total_kwh).abs() < 1e-6,
+            "heating power exactly offsetting the loss at a constant ambient temperature should \
+             reproduce the steady-state energy use: transient {} vs steady {}",
+            transient.q_total_kwh,
+            steady.q_total_kwh
+        );
+    }
 }